@@ -1,4 +1,6 @@
-use syn::{spanned::Spanned, FnArg, ImplItem, ItemImpl, Pat, PatIdent, Signature, Type};
+use syn::{
+    parse::Parser, spanned::Spanned, FnArg, ImplItem, ItemImpl, Pat, PatIdent, Signature, Type,
+};
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{quote, ToTokens};
@@ -53,23 +55,284 @@ impl ToTokens for RpcMode {
 pub(crate) struct ClassMethodExport {
     pub(crate) class_ty: Box<Type>,
     pub(crate) methods: Vec<ExportMethod>,
+    pub(crate) mixin: MixinKind,
+}
+
+/// How a single `#[methods]` impl block contributes to a class's
+/// `NativeClassMethods` registration.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub(crate) enum MixinKind {
+    /// Emit the `NativeClassMethods` impl directly (the default).
+    None,
+    /// This block only contributes a fragment named `Name`; emit a standalone
+    /// registration function instead of the full trait impl.
+    Fragment(String),
+    /// This is the main block for the class; in addition to its own methods,
+    /// invoke the named fragments' registration functions during `register`.
+    Collector(Vec<String>),
+}
+
+/// Checks that `self_ty` (the type the `impl` block is for) is fully concrete,
+/// i.e. free of unbound type or const parameters declared on `generics`, elided
+/// or anonymous lifetimes, and `_` type holes. Recurses into generic arguments,
+/// references, slices, arrays and tuples so nested occurrences are caught too.
+fn check_self_ty_is_concrete(self_ty: &Type, generics: &syn::Generics) -> Result<(), syn::Error> {
+    let type_params: std::collections::HashSet<String> = generics
+        .type_params()
+        .map(|param| param.ident.to_string())
+        .collect();
+    let const_params: std::collections::HashSet<String> = generics
+        .const_params()
+        .map(|param| param.ident.to_string())
+        .collect();
+
+    check_type_is_concrete(self_ty, &type_params, &const_params)
+}
+
+fn check_type_is_concrete(
+    ty: &Type,
+    type_params: &std::collections::HashSet<String>,
+    const_params: &std::collections::HashSet<String>,
+) -> Result<(), syn::Error> {
+    match ty {
+        Type::Path(type_path) => {
+            if let Some(ident) = type_path.path.get_ident() {
+                if type_params.contains(&ident.to_string()) {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "exported classes must have a fully concrete type; found unbound type parameter",
+                    ));
+                }
+                // A bare const parameter used as a generic argument (the `N` in
+                // `MyClass<N>`) parses as a type path.
+                if const_params.contains(&ident.to_string()) {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "exported classes must have a fully concrete type; found unbound const parameter",
+                    ));
+                }
+            }
+
+            for segment in &type_path.path.segments {
+                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                    for arg in &args.args {
+                        match arg {
+                            syn::GenericArgument::Type(ty) => {
+                                check_type_is_concrete(ty, type_params, const_params)?
+                            }
+                            syn::GenericArgument::Lifetime(lifetime) => {
+                                check_lifetime_is_concrete(lifetime)?
+                            }
+                            syn::GenericArgument::Const(expr) => {
+                                check_const_arg_is_concrete(expr, const_params)?
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        }
+        Type::Reference(reference) => {
+            match &reference.lifetime {
+                Some(lifetime) => check_lifetime_is_concrete(lifetime)?,
+                None => {
+                    return Err(syn::Error::new(
+                        reference.and_token.span(),
+                        "exported classes must have a fully concrete type; found an elided or anonymous lifetime",
+                    ))
+                }
+            }
+            check_type_is_concrete(&reference.elem, type_params, const_params)
+        }
+        Type::Slice(slice) => check_type_is_concrete(&slice.elem, type_params, const_params),
+        Type::Array(array) => {
+            check_const_arg_is_concrete(&array.len, const_params)?;
+            check_type_is_concrete(&array.elem, type_params, const_params)
+        }
+        Type::Tuple(tuple) => {
+            for elem in &tuple.elems {
+                check_type_is_concrete(elem, type_params, const_params)?;
+            }
+            Ok(())
+        }
+        Type::Infer(infer) => Err(syn::Error::new(
+            infer.span(),
+            "exported classes must have a fully concrete type; found `_`",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Flags a const argument expression that is an unbound const parameter declared
+/// on the enclosing `impl` block, either bare (the `N` in `[u8; N]`) or braced
+/// (the `{ N }` in `MyClass<{ N }>`). A bare `N` generic argument, as in
+/// `MyClass<N>`, parses as a type and is caught by `check_type_is_concrete`.
+fn check_const_arg_is_concrete(
+    expr: &syn::Expr,
+    const_params: &std::collections::HashSet<String>,
+) -> Result<(), syn::Error> {
+    let expr = match expr {
+        syn::Expr::Block(block) => match block.block.stmts.as_slice() {
+            [syn::Stmt::Expr(inner)] => inner,
+            _ => expr,
+        },
+        _ => expr,
+    };
+
+    if let syn::Expr::Path(expr_path) = expr {
+        if let Some(ident) = expr_path.path.get_ident() {
+            if const_params.contains(&ident.to_string()) {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "exported classes must have a fully concrete type; found unbound const parameter",
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn check_lifetime_is_concrete(lifetime: &syn::Lifetime) -> Result<(), syn::Error> {
+    if lifetime.ident == "_" {
+        Err(syn::Error::new(
+            lifetime.span(),
+            "exported classes must have a fully concrete type; found an elided or anonymous lifetime",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Parses the `mixin = "Name"` / `mixins = "A, B"` options out of the
+/// `#[methods(...)]` attribute arguments.
+fn parse_mixin_args(meta: TokenStream2) -> Result<MixinKind, syn::Error> {
+    use syn::{punctuated::Punctuated, Meta, NestedMeta, Token};
+
+    if meta.is_empty() {
+        return Ok(MixinKind::None);
+    }
+
+    let nested = Punctuated::<NestedMeta, Token![,]>::parse_terminated
+        .parse2(meta)
+        .map_err(|err| syn::Error::new(err.span(), format!("invalid `methods` arguments: {}", err)))?;
+
+    let mut mixin = None;
+
+    for item in nested {
+        let (path, lit) = match item {
+            NestedMeta::Meta(Meta::NameValue(syn::MetaNameValue { path, lit, .. })) => (path, lit),
+            unexpected => {
+                return Err(syn::Error::new(
+                    unexpected.span(),
+                    "unexpected argument for methods",
+                ))
+            }
+        };
+
+        let last = path
+            .segments
+            .last()
+            .ok_or_else(|| syn::Error::new(path.span(), "the path should not be empty"))?;
+
+        let value = match lit {
+            syn::Lit::Str(lit_str) => lit_str.value(),
+            _ => return Err(syn::Error::new(last.span(), "expected a string literal")),
+        };
+
+        let kind = match last.ident.to_string().as_str() {
+            "mixin" => MixinKind::Fragment(value),
+            "mixins" => {
+                let names = value
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+                MixinKind::Collector(names)
+            }
+            unknown => {
+                return Err(syn::Error::new(
+                    last.span(),
+                    format!("unknown option for methods: `{}`", unknown),
+                ))
+            }
+        };
+
+        if mixin.replace(kind).is_some() {
+            return Err(syn::Error::new(
+                last.span(),
+                "`mixin`/`mixins` was set more than once",
+            ));
+        }
+    }
+
+    Ok(mixin.unwrap_or(MixinKind::None))
+}
+
+/// Produces a unique, deterministic identifier for the registration function of
+/// a named mixin fragment on a given class.
+fn mixin_register_fn_ident(class_ty: &Type, mixin_name: &str) -> syn::Ident {
+    let sanitize = |s: String| -> String {
+        s.chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect()
+    };
+
+    let class_part = sanitize(quote::quote!(#class_ty).to_string());
+    let mixin_part = sanitize(mixin_name.to_string());
+
+    syn::Ident::new(
+        &format!("__godot_rust_mixin_{}_{}", class_part, mixin_part),
+        proc_macro2::Span::call_site(),
+    )
+}
+
+/// The role a single argument of an exported method plays, as classified from its
+/// position (`self`) or attributes (`#[base]`).
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub(crate) enum ArgKind {
+    /// The `self`/`&mut self` receiver.
+    Receiver,
+    /// The argument marked `#[base]`, to be bound to the owner regardless of position.
+    Base,
+    /// The argument marked `#[async_ctx]`, to be bound to the async executor context.
+    AsyncCtx,
+    /// A regular, Variant-convertible argument.
+    Regular { optional: bool },
 }
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 pub(crate) struct ExportMethod {
     pub(crate) sig: Signature,
     pub(crate) args: ExportArgs,
+    pub(crate) arg_kinds: Vec<ArgKind>,
 }
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub(crate) struct ExportArgs {
     pub(crate) optional_args: Option<usize>,
     pub(crate) rpc_mode: RpcMode,
+    /// Overrides the GDScript-facing method name, set via `#[export(name = "...")]`.
+    pub(crate) name: Option<String>,
+    /// Set via `#[export(deref_return)]`; dereferences the return value before
+    /// handing it to Godot.
+    pub(crate) deref_return: bool,
 }
 
-pub(crate) fn derive_methods(item_impl: ItemImpl) -> TokenStream2 {
+pub(crate) fn derive_methods(meta: TokenStream2, item_impl: ItemImpl) -> TokenStream2 {
+    if let Err(err) = check_self_ty_is_concrete(&item_impl.self_ty, &item_impl.generics) {
+        return err.to_compile_error();
+    }
+
+    let mixin = match parse_mixin_args(meta) {
+        Ok(mixin) => mixin,
+        Err(err) => return err.to_compile_error(),
+    };
+
     let derived = crate::automatically_derived();
-    let (impl_block, export) = impl_gdnative_expose(item_impl);
+    let (impl_block, export) = impl_gdnative_expose(item_impl, mixin);
 
     let class_name = export.class_ty;
 
@@ -78,86 +341,145 @@ pub(crate) fn derive_methods(item_impl: ItemImpl) -> TokenStream2 {
     let methods = export
         .methods
         .into_iter()
-        .map(|ExportMethod { sig, args }| {
+        .map(|ExportMethod { sig, args, arg_kinds }| {
             let sig_span = sig.ident.span();
 
             let name = sig.ident;
-            let name_string = name.to_string();
+            let name_string = args.name.clone().unwrap_or_else(|| name.to_string());
             let ret_span = sig.output.span();
             let ret_ty = match sig.output {
                 syn::ReturnType::Default => quote_spanned!(ret_span => ()),
                 syn::ReturnType::Type(_, ty) => quote_spanned!( ret_span => #ty ),
             };
 
-            let arg_count = sig.inputs.len();
+            if !matches!(arg_kinds.first(), Some(ArgKind::Receiver)) {
+                return syn::Error::new(sig_span, "exported methods must take self as an argument")
+                    .to_compile_error();
+            }
+
+            let max_optional = arg_kinds
+                .iter()
+                .filter(|kind| matches!(kind, ArgKind::Regular { .. }))
+                .count();
 
-            if arg_count < 2 {
+            if let Some(count) = args.optional_args {
+                if count > max_optional {
+                    let message = format!(
+                        "there can be at most {} optional arguments, got {}",
+                        max_optional, count,
+                    );
+                    return syn::Error::new(sig_span, message).to_compile_error();
+                }
+            }
+
+            let rpc = args.rpc_mode;
+            let deref_return = args.deref_return;
+
+            let is_async = arg_kinds.iter().any(|kind| matches!(kind, ArgKind::AsyncCtx));
+
+            if sig.asyncness.is_some() && !is_async {
                 return syn::Error::new(
                     sig_span,
-                    "exported methods must take self and owner as arguments",
+                    "async exported methods must take a `#[async_ctx]` argument",
                 )
                 .to_compile_error();
             }
 
-            let optional_args = match args.optional_args {
-                Some(count) => {
-                    let max_optional = arg_count - 2; // self and owner
-                    if count > max_optional {
-                        let message = format!(
-                            "there can be at most {} optional arguments, got {}",
-                            max_optional, count,
-                        );
-                        return syn::Error::new(sig_span, message).to_compile_error();
+            let args = sig.inputs.iter().zip(arg_kinds.iter()).map(|(arg, kind)| {
+                let span = arg.span();
+                match kind {
+                    ArgKind::AsyncCtx => quote_spanned!(span => #[async_ctx] #arg ,),
+                    ArgKind::Base => quote_spanned!(span => #[base] #arg ,),
+                    ArgKind::Regular { optional: true } => {
+                        quote_spanned!(span => #[opt] #arg ,)
+                    }
+                    ArgKind::Regular { optional: false } | ArgKind::Receiver => {
+                        quote_spanned!(span => #arg ,)
                     }
-                    count
                 }
-                None => 0,
-            };
+            });
 
-            let rpc = args.rpc_mode;
+            let register_call = if is_async {
+                quote_spanned!(sig_span => done_stateless_async())
+            } else {
+                quote_spanned!(sig_span => done_stateless())
+            };
 
-            let args = sig.inputs.iter().enumerate().map(|(n, arg)| {
-                let span = arg.span();
-                if n < arg_count - optional_args {
-                    quote_spanned!(span => #arg ,)
-                } else {
-                    quote_spanned!(span => #[opt] #arg ,)
-                }
-            });
+            let deref_return = if deref_return {
+                quote_spanned!(ret_span => #[deref_return])
+            } else {
+                quote_spanned!(ret_span =>)
+            };
 
             quote_spanned!( sig_span=>
                 {
                     let method = ::gdnative::godot_wrap_method!(
                         #class_name,
+                        #deref_return
                         fn #name ( #( #args )* ) -> #ret_ty
                     );
 
                     #builder.build_method(#name_string, method)
                         .with_rpc_mode(#rpc)
-                        .done_stateless();
+                        .#register_call;
                 }
             )
         })
         .collect::<Vec<_>>();
 
-    quote::quote!(
-        #impl_block
+    match export.mixin {
+        MixinKind::None => quote::quote!(
+            #impl_block
 
-        #derived
-        impl gdnative::nativescript::NativeClassMethods for #class_name {
-            fn register(#builder: &::gdnative::nativescript::init::ClassBuilder<Self>) {
-                use gdnative::nativescript::init::*;
+            #derived
+            impl gdnative::nativescript::NativeClassMethods for #class_name {
+                fn register(#builder: &::gdnative::nativescript::init::ClassBuilder<Self>) {
+                    use gdnative::nativescript::init::*;
 
-                #(#methods)*
+                    #(#methods)*
+                }
             }
+        ),
+        MixinKind::Fragment(name) => {
+            let register_fn = mixin_register_fn_ident(&class_name, &name);
+
+            quote::quote!(
+                #impl_block
+
+                #[doc(hidden)]
+                #[allow(non_snake_case)]
+                fn #register_fn(#builder: &::gdnative::nativescript::init::ClassBuilder<#class_name>) {
+                    use gdnative::nativescript::init::*;
+
+                    #(#methods)*
+                }
+            )
         }
+        MixinKind::Collector(mixin_names) => {
+            let mixin_register_fns = mixin_names
+                .iter()
+                .map(|name| mixin_register_fn_ident(&class_name, name));
 
-    )
+            quote::quote!(
+                #impl_block
+
+                #derived
+                impl gdnative::nativescript::NativeClassMethods for #class_name {
+                    fn register(#builder: &::gdnative::nativescript::init::ClassBuilder<Self>) {
+                        use gdnative::nativescript::init::*;
+
+                        #(#methods)*
+                        #( #mixin_register_fns(#builder); )*
+                    }
+                }
+            )
+        }
+    }
 }
 
 /// Extract the data to export from the impl block.
 #[allow(clippy::single_match)]
-fn impl_gdnative_expose(ast: ItemImpl) -> (ItemImpl, ClassMethodExport) {
+fn impl_gdnative_expose(ast: ItemImpl, mixin: MixinKind) -> (ItemImpl, ClassMethodExport) {
     // the ast input is used for inspecting.
     // this clone is used to remove all attributes so that the resulting
     // impl block actually compiles again.
@@ -170,6 +492,7 @@ fn impl_gdnative_expose(ast: ItemImpl) -> (ItemImpl, ClassMethodExport) {
     let mut export = ClassMethodExport {
         class_ty: ast.self_ty,
         methods: vec![],
+        mixin,
     };
 
     let mut methods_to_export: Vec<ExportMethod> = Vec::new();
@@ -207,88 +530,132 @@ fn impl_gdnative_expose(ast: ItemImpl) -> (ItemImpl, ClassMethodExport) {
                                     }
                                 };
 
-                                let pairs: Vec<_> = match meta {
-                                    Meta::List(list) => list
-                                        .nested
-                                        .into_pairs()
-                                        .filter_map(|p| {
-                                            let span = p.span();
-                                            match p.into_value() {
-                                                NestedMeta::Meta(Meta::NameValue(pair)) => {
-                                                    Some(pair)
+                                let nested: Vec<NestedMeta> = match meta {
+                                    Meta::List(list) => list.nested.into_iter().collect(),
+                                    Meta::NameValue(pair) => vec![NestedMeta::Meta(Meta::NameValue(pair))],
+                                    Meta::Path(path) => vec![NestedMeta::Meta(Meta::Path(path))],
+                                };
+
+                                for item in nested {
+                                    match item {
+                                        NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                                            path,
+                                            lit,
+                                            ..
+                                        })) => {
+                                            let last = match path.segments.last() {
+                                                Some(val) => val,
+                                                None => {
+                                                    errors.push(syn::Error::new(
+                                                        path.span(),
+                                                        "the path should not be empty",
+                                                    ));
+                                                    continue;
+                                                }
+                                            };
+                                            let path = last.ident.to_string();
+
+                                            match path.as_str() {
+                                                "rpc" => {
+                                                    let value = if let syn::Lit::Str(lit_str) = lit
+                                                    {
+                                                        lit_str.value()
+                                                    } else {
+                                                        errors.push(syn::Error::new(
+                                                            last.span(),
+                                                            "unexpected type for rpc value, expected Str",
+                                                        ));
+                                                        continue;
+                                                    };
+
+                                                    if let Some(mode) =
+                                                        RpcMode::parse(value.as_str())
+                                                    {
+                                                        if rpc.replace(mode).is_some() {
+                                                            errors.push(syn::Error::new(
+                                                                last.span(),
+                                                                "rpc mode was set more than once",
+                                                            ));
+                                                        }
+                                                    } else {
+                                                        errors.push(syn::Error::new(
+                                                            last.span(),
+                                                            format!(
+                                                                "unexpected value for rpc: {}",
+                                                                value
+                                                            ),
+                                                        ));
+                                                    }
                                                 }
-                                                unexpected => {
+                                                "name" => {
+                                                    let value = if let syn::Lit::Str(lit_str) = lit
+                                                    {
+                                                        lit_str.value()
+                                                    } else {
+                                                        errors.push(syn::Error::new(
+                                                            last.span(),
+                                                            "unexpected type for name value, expected Str",
+                                                        ));
+                                                        continue;
+                                                    };
+
+                                                    if _export_args.name.replace(value).is_some() {
+                                                        errors.push(syn::Error::new(
+                                                            last.span(),
+                                                            "name was set more than once",
+                                                        ));
+                                                    }
+                                                }
+                                                _ => {
                                                     let msg = format!(
-                                                        "unexpected argument in list: {}",
-                                                        unexpected.into_token_stream()
+                                                        "unknown option for export: `{}`",
+                                                        path
                                                     );
-                                                    errors.push(syn::Error::new(span, msg));
-                                                    None
+                                                    errors.push(syn::Error::new(last.span(), msg));
                                                 }
                                             }
-                                        })
-                                        .collect(),
-                                    Meta::NameValue(pair) => vec![pair],
-                                    meta => {
-                                        let span = meta.span();
-                                        let msg = format!(
-                                            "unexpected attribute argument: {}",
-                                            meta.into_token_stream()
-                                        );
-                                        errors.push(syn::Error::new(span, msg));
-                                        return false;
-                                    }
-                                };
-
-                                for MetaNameValue { path, lit, .. } in pairs {
-                                    let last = match path.segments.last() {
-                                        Some(val) => val,
-                                        None => {
-                                            errors.push(syn::Error::new(
-                                                path.span(),
-                                                "the path should not be empty",
-                                            ));
-                                            return false;
                                         }
-                                    };
-                                    let path = last.ident.to_string();
-
-                                    // Match rpc mode
-                                    match path.as_str() {
-                                        "rpc" => {
-                                            let value = if let syn::Lit::Str(lit_str) = lit {
-                                                lit_str.value()
-                                            } else {
-                                                errors.push(syn::Error::new(
-                                                    last.span(),
-                                                    "unexpected type for rpc value, expected Str",
-                                                ));
-                                                return false;
-                                            };
-
-                                            if let Some(mode) = RpcMode::parse(value.as_str()) {
-                                                if rpc.replace(mode).is_some() {
+                                        NestedMeta::Meta(Meta::Path(path)) => {
+                                            let last = match path.segments.last() {
+                                                Some(val) => val,
+                                                None => {
                                                     errors.push(syn::Error::new(
-                                                        last.span(),
-                                                        "rpc mode was set more than once",
+                                                        path.span(),
+                                                        "the path should not be empty",
                                                     ));
-                                                    return false;
+                                                    continue;
                                                 }
-                                            } else {
-                                                errors.push(syn::Error::new(
-                                                    last.span(),
-                                                    format!("unexpected value for rpc: {}", value),
-                                                ));
-                                                return false;
-                                            }
+                                            };
 
-                                            return false;
+                                            match last.ident.to_string().as_str() {
+                                                "deref_return" => {
+                                                    if _export_args.deref_return {
+                                                        errors.push(syn::Error::new(
+                                                            last.span(),
+                                                            "deref_return was set more than once",
+                                                        ));
+                                                    } else {
+                                                        _export_args.deref_return = true;
+                                                    }
+                                                }
+                                                unknown => {
+                                                    let msg = format!(
+                                                        "unknown option for export: `{}`",
+                                                        unknown
+                                                    );
+                                                    errors.push(syn::Error::new(last.span(), msg));
+                                                }
+                                            }
+                                        }
+                                        unexpected => {
+                                            let span = unexpected.span();
+                                            let msg = format!(
+                                                "unexpected argument in list: {}",
+                                                unexpected.into_token_stream()
+                                            );
+                                            errors.push(syn::Error::new(span, msg));
                                         }
-                                        _ => (),
                                     }
-
-                                    let msg = format!("unknown option for export: `{}`", path);
-                                    errors.push(syn::Error::new(last.span(), msg));
                                 }
                             }
 
@@ -301,41 +668,103 @@ fn impl_gdnative_expose(ast: ItemImpl) -> (ItemImpl, ClassMethodExport) {
 
                 if let Some(mut export_args) = export_args.take() {
                     let mut optional_args = None;
+                    let mut arg_kinds = Vec::with_capacity(method.sig.inputs.len());
+                    let mut has_base = false;
+                    let mut has_async_ctx = false;
 
-                    for (n, arg) in method.sig.inputs.iter_mut().enumerate() {
+                    for arg in method.sig.inputs.iter_mut() {
                         let attrs = match arg {
                             FnArg::Receiver(a) => &mut a.attrs,
                             FnArg::Typed(a) => &mut a.attrs,
                         };
 
                         let mut is_optional = false;
+                        let mut is_base = false;
+                        let mut is_async_ctx = false;
 
                         attrs.retain(|attr| {
                             if attr.path.is_ident("opt") {
                                 is_optional = true;
                                 false
+                            } else if attr.path.is_ident("base") {
+                                is_base = true;
+                                false
+                            } else if attr.path.is_ident("async_ctx") {
+                                is_async_ctx = true;
+                                false
                             } else {
                                 true
                             }
                         });
 
-                        if is_optional {
-                            if n < 2 {
+                        // Every branch below always produces a `kind` (even when it records
+                        // an error), so `arg_kinds` stays in lockstep with `sig.inputs` for
+                        // the `zip()` in codegen regardless of how many errors are found.
+                        let kind = if matches!(arg, FnArg::Receiver(_)) {
+                            if is_optional || is_base || is_async_ctx {
                                 errors.push(syn::Error::new(
                                     arg.span(),
-                                    "self or owner cannot be optional",
+                                    "self cannot be optional or marked as the base or async context argument",
+                                ));
+                            }
+                            ArgKind::Receiver
+                        } else if is_base {
+                            if has_base {
+                                errors.push(syn::Error::new(
+                                    arg.span(),
+                                    "there can be at most one base argument",
+                                ));
+                                ArgKind::Regular { optional: false }
+                            } else if is_optional || is_async_ctx {
+                                errors.push(syn::Error::new(
+                                    arg.span(),
+                                    "the base argument cannot be optional or the async context",
+                                ));
+                                ArgKind::Regular { optional: false }
+                            } else {
+                                has_base = true;
+                                ArgKind::Base
+                            }
+                        } else if is_async_ctx {
+                            if has_async_ctx {
+                                errors.push(syn::Error::new(
+                                    arg.span(),
+                                    "there can be at most one async context argument",
+                                ));
+                                ArgKind::Regular { optional: false }
+                            } else if is_optional {
+                                errors.push(syn::Error::new(
+                                    arg.span(),
+                                    "the async context argument cannot be optional",
+                                ));
+                                ArgKind::Regular { optional: false }
+                            } else {
+                                has_async_ctx = true;
+                                ArgKind::AsyncCtx
+                            }
+                        } else {
+                            if is_optional {
+                                *optional_args.get_or_insert(0) += 1;
+                            } else if optional_args.is_some() {
+                                errors.push(syn::Error::new(
+                                    arg.span(),
+                                    "cannot add required parameters after optional ones",
                                 ));
-                                continue;
                             }
 
-                            *optional_args.get_or_insert(0) += 1;
-                        } else if optional_args.is_some() {
-                            errors.push(syn::Error::new(
-                                arg.span(),
-                                "cannot add required parameters after optional ones",
-                            ));
-                            continue;
-                        }
+                            ArgKind::Regular {
+                                optional: is_optional,
+                            }
+                        };
+
+                        arg_kinds.push(kind);
+                    }
+
+                    if has_async_ctx && method.sig.asyncness.is_none() {
+                        errors.push(syn::Error::new(
+                            method.sig.ident.span(),
+                            "#[async_ctx] can only be used in `async fn`",
+                        ));
                     }
 
                     export_args.optional_args = optional_args;
@@ -344,6 +773,7 @@ fn impl_gdnative_expose(ast: ItemImpl) -> (ItemImpl, ClassMethodExport) {
                     methods_to_export.push(ExportMethod {
                         sig: method.sig.clone(),
                         args: export_args,
+                        arg_kinds,
                     });
                 }
 
@@ -429,3 +859,112 @@ fn impl_gdnative_expose(ast: ItemImpl) -> (ItemImpl, ClassMethodExport) {
 
     (result, export)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(meta: TokenStream2, item_impl: ItemImpl) -> String {
+        derive_methods(meta, item_impl).to_string()
+    }
+
+    #[test]
+    fn base_argument_is_recognized_in_a_non_first_position() {
+        let item_impl: ItemImpl = syn::parse_quote! {
+            impl MyClass {
+                #[export]
+                fn with_late_base(&self, value: i64, #[base] owner: &Node) -> i64 {
+                    value
+                }
+            }
+        };
+
+        let output = expand(TokenStream2::new(), item_impl);
+
+        assert!(output.contains("value : i64 , # [base] owner : & Node"));
+    }
+
+    #[test]
+    fn async_export_keeps_its_async_ctx_argument() {
+        let item_impl: ItemImpl = syn::parse_quote! {
+            impl MyClass {
+                #[export]
+                async fn do_async(&self, #[async_ctx] ctx: Arc<Context>) -> i64 {
+                    0
+                }
+            }
+        };
+
+        let output = expand(TokenStream2::new(), item_impl);
+
+        assert!(output.contains("# [async_ctx] ctx : Arc < Context >"));
+        assert!(output.contains("done_stateless_async ()"));
+    }
+
+    #[test]
+    fn async_export_without_async_ctx_is_rejected() {
+        let item_impl: ItemImpl = syn::parse_quote! {
+            impl MyClass {
+                #[export]
+                async fn do_async(&self) -> i64 {
+                    0
+                }
+            }
+        };
+
+        let output = expand(TokenStream2::new(), item_impl);
+
+        assert!(output.contains("async exported methods must take a `#[async_ctx]` argument"));
+    }
+
+    #[test]
+    fn mixin_fragment_and_collector_share_the_same_register_fn() {
+        let fragment_impl: ItemImpl = syn::parse_quote! {
+            impl MyClass {
+                #[export]
+                fn from_fragment(&self) -> i64 {
+                    0
+                }
+            }
+        };
+        let fragment_meta: TokenStream2 = syn::parse_quote!(mixin = "Stuff");
+
+        let fragment_output = expand(fragment_meta, fragment_impl);
+
+        assert!(fragment_output.contains("fn __godot_rust_mixin_MyClass_Stuff"));
+        assert!(!fragment_output.contains("NativeClassMethods"));
+
+        let collector_impl: ItemImpl = syn::parse_quote! {
+            impl MyClass {
+                #[export]
+                fn from_main(&self) -> i64 {
+                    0
+                }
+            }
+        };
+        let collector_meta: TokenStream2 = syn::parse_quote!(mixins = "Stuff");
+
+        let collector_output = expand(collector_meta, collector_impl);
+
+        assert!(collector_output.contains("impl gdnative :: nativescript :: NativeClassMethods for MyClass"));
+        assert!(collector_output.contains("__godot_rust_mixin_MyClass_Stuff (builder) ;"));
+    }
+
+    #[test]
+    fn export_options_override_name_and_set_deref_return() {
+        let item_impl: ItemImpl = syn::parse_quote! {
+            impl MyClass {
+                #[export(name = "renamed", deref_return)]
+                fn original_name(&self) -> Ref<Node> {
+                    unreachable!()
+                }
+            }
+        };
+
+        let output = expand(TokenStream2::new(), item_impl);
+
+        assert!(output.contains("build_method (\"renamed\" , method)"));
+        assert!(!output.contains("\"original_name\""));
+        assert!(output.contains("# [deref_return]"));
+    }
+}